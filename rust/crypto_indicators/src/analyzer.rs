@@ -0,0 +1,142 @@
+use crate::hash_ribbons::{self, HashRibbonsResult};
+use crate::mvrv::{self, MVRVResult};
+use crate::puell::{self, PuellResult};
+use crate::sopr::{self, SOPRResult};
+
+/// Aligned, optional input series for the unified Analyzer. Any subset may be
+/// supplied; only the indicators whose required series are all present are run.
+#[derive(Clone, Debug, Default)]
+pub struct AnalyzerInputs {
+    pub market_cap: Option<Vec<f64>>,
+    pub realized_cap: Option<Vec<f64>>,
+    pub sopr: Option<Vec<f64>>,
+    pub issuance: Option<Vec<f64>>,
+    pub issuance_ma_365: Option<Vec<f64>>,
+    pub hash_rate: Option<Vec<f64>>,
+    pub difficulty: Option<Vec<f64>>,
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct AnalyzerResult {
+    pub mvrv: Option<MVRVResult>,
+    pub sopr: Option<SOPRResult>,
+    pub puell: Option<PuellResult>,
+    pub hash_ribbons: Option<HashRibbonsResult>,
+    pub market_cycle_phase: String,
+    pub confidence: f64,
+}
+
+/// Runs whichever indicators have their required inputs present over one common,
+/// overlapping window: every supplied series is truncated to the shortest
+/// supplied series' length, keeping each series' most recent observations, so
+/// mismatched-length inputs from the caller still line up bar-for-bar. The
+/// individual phase reads (MVRV market_phase, Puell market_cycle_phase, Hash
+/// Ribbons mining_health) are then reconciled into one consensus label, with
+/// `confidence` equal to one minus the standard deviation of their [-1, +1]
+/// phase votes (clamped to [0, 1]), so tighter agreement gives higher confidence.
+pub fn run_analysis(inputs: AnalyzerInputs) -> AnalyzerResult {
+    let common_len = [
+        inputs.market_cap.as_ref().map(|v| v.len()),
+        inputs.realized_cap.as_ref().map(|v| v.len()),
+        inputs.sopr.as_ref().map(|v| v.len()),
+        inputs.issuance.as_ref().map(|v| v.len()),
+        inputs.issuance_ma_365.as_ref().map(|v| v.len()),
+        inputs.hash_rate.as_ref().map(|v| v.len()),
+        inputs.difficulty.as_ref().map(|v| v.len()),
+    ]
+    .into_iter()
+    .flatten()
+    .min();
+
+    let Some(common_len) = common_len else {
+        return AnalyzerResult {
+            market_cycle_phase: "Insufficient Data".to_string(),
+            ..Default::default()
+        };
+    };
+
+    let trim = |series: &Option<Vec<f64>>| -> Option<Vec<f64>> {
+        series.as_ref().map(|v| v[v.len() - common_len..].to_vec())
+    };
+
+    let market_cap = trim(&inputs.market_cap);
+    let realized_cap = trim(&inputs.realized_cap);
+    let sopr_series = trim(&inputs.sopr);
+    let issuance = trim(&inputs.issuance);
+    let issuance_ma_365 = trim(&inputs.issuance_ma_365);
+    let hash_rate = trim(&inputs.hash_rate);
+    let difficulty = trim(&inputs.difficulty);
+
+    let mvrv_res = match (&market_cap, &realized_cap) {
+        (Some(mc), Some(rc)) => Some(mvrv::compute_mvrv(mc, rc)),
+        _ => None,
+    };
+    let sopr_res = sopr_series.as_ref().map(|s| sopr::compute_sopr(s));
+    let puell_res = match (&issuance, &issuance_ma_365) {
+        (Some(iss), Some(ma)) => Some(puell::compute_puell(iss, ma)),
+        _ => None,
+    };
+    let hash_ribbons_res = hash_rate
+        .as_ref()
+        .map(|hr| hash_ribbons::compute_hash_ribbons(hr, difficulty.as_deref()));
+
+    let mut votes: Vec<f64> = Vec::new();
+    if let Some(r) = &mvrv_res {
+        votes.push(phase_vote(&r.market_phase));
+    }
+    if let Some(r) = &puell_res {
+        votes.push(phase_vote(&r.market_cycle_phase));
+    }
+    if let Some(r) = &hash_ribbons_res {
+        votes.push(phase_vote(&r.mining_health));
+    }
+
+    let (market_cycle_phase, confidence) = if votes.is_empty() {
+        ("Insufficient Data".to_string(), 0.0)
+    } else {
+        let mean_vote = votes.iter().sum::<f64>() / votes.len() as f64;
+        let dispersion = (votes.iter().map(|v| (v - mean_vote).powi(2)).sum::<f64>() / votes.len() as f64).sqrt();
+        (determine_consensus_phase(mean_vote), (1.0 - dispersion).clamp(0.0, 1.0))
+    };
+
+    AnalyzerResult {
+        mvrv: mvrv_res,
+        sopr: sopr_res,
+        puell: puell_res,
+        hash_ribbons: hash_ribbons_res,
+        market_cycle_phase,
+        confidence,
+    }
+}
+
+/// Maps a free-text indicator phase/health string onto a normalized [-1, +1]
+/// bullish-bearish vote so heterogeneous phase labels can be averaged.
+fn phase_vote(phase: &str) -> f64 {
+    if phase.contains("Extreme Euphoria") || phase.contains("Cycle Top") || phase.contains("Capitulation") {
+        -1.0
+    } else if phase.contains("Euphoria") || phase.contains("Late Bull") || phase.contains("Concerning") {
+        -0.5
+    } else if phase.contains("Excellent") || phase.contains("Extreme Fear") || phase.contains("Cycle Bottom") {
+        1.0
+    } else if phase.contains("Good") || phase.contains("Bear Market") || phase.contains("Pessimism") {
+        0.5
+    } else if phase.contains("Optimism") || phase.contains("Bull Market") {
+        0.3
+    } else {
+        0.0
+    }
+}
+
+fn determine_consensus_phase(mean_vote: f64) -> String {
+    if mean_vote > 0.6 {
+        "Deep Accumulation - Strong Undervaluation".to_string()
+    } else if mean_vote > 0.2 {
+        "Accumulation - Mild Undervaluation".to_string()
+    } else if mean_vote > -0.2 {
+        "Neutral - Balanced Cycle".to_string()
+    } else if mean_vote > -0.6 {
+        "Distribution - Mild Overvaluation".to_string()
+    } else {
+        "Euphoria - Strong Overvaluation".to_string()
+    }
+}
@@ -0,0 +1,165 @@
+#[derive(Clone, Debug)]
+pub struct BacktestResult {
+    pub equity_curve: Vec<f64>,
+    pub total_return: f64,
+    pub annualized_return: f64,
+    pub sharpe_ratio: f64,
+    pub sortino_ratio: f64,
+    pub calmar_ratio: f64,
+    pub max_drawdown: f64,
+    pub max_drawdown_duration: usize,
+    pub win_rate: f64,
+    pub profit_factor: f64,
+    pub avg_win: f64,
+    pub avg_loss: f64,
+    pub trade_count: usize,
+}
+
+/// Simulates holding `positions[t-1]` (+1 long, -1 short, 0 flat) into bar t and
+/// scores the resulting equity curve with standard backtest performance metrics,
+/// so any indicator's Buy/Sell/Hold signal string can be converted to a position
+/// series in Python and compared on the same price history.
+pub fn backtest_signals(price: &[f64], positions: &[f64], periods_per_year: f64) -> BacktestResult {
+    let n = price.len().min(positions.len());
+    if n < 2 {
+        return BacktestResult {
+            equity_curve: vec![1.0; n],
+            total_return: 0.0,
+            annualized_return: 0.0,
+            sharpe_ratio: 0.0,
+            sortino_ratio: 0.0,
+            calmar_ratio: 0.0,
+            max_drawdown: 0.0,
+            max_drawdown_duration: 0,
+            win_rate: 0.0,
+            profit_factor: 0.0,
+            avg_win: 0.0,
+            avg_loss: 0.0,
+            trade_count: 0,
+        };
+    }
+
+    let mut returns = vec![0.0; n];
+    let mut equity_curve = vec![1.0; n];
+    for i in 1..n {
+        let prev_price = price[i - 1];
+        let raw_ret = if prev_price.abs() > 1e-12 { price[i] / prev_price - 1.0 } else { 0.0 };
+        returns[i] = positions[i - 1] * raw_ret;
+        equity_curve[i] = equity_curve[i - 1] * (1.0 + returns[i]);
+    }
+
+    let total_return = equity_curve[n - 1] - 1.0;
+    let annualized_return = if equity_curve[n - 1] > 0.0 {
+        equity_curve[n - 1].powf(periods_per_year / n as f64) - 1.0
+    } else {
+        -1.0
+    };
+
+    let bar_returns = &returns[1..];
+    let mean_ret = mean(bar_returns);
+    let std_ret = stddev(bar_returns, mean_ret);
+    let sharpe_ratio = if std_ret > 1e-12 { mean_ret / std_ret * periods_per_year.sqrt() } else { 0.0 };
+
+    let downside: Vec<f64> = bar_returns.iter().map(|r| r.min(0.0)).collect();
+    let downside_std = (downside.iter().map(|d| d * d).sum::<f64>() / downside.len().max(1) as f64).sqrt();
+    let sortino_ratio = if downside_std > 1e-12 { mean_ret / downside_std * periods_per_year.sqrt() } else { 0.0 };
+
+    let (max_drawdown, max_drawdown_duration) = drawdown_stats(&equity_curve);
+    let calmar_ratio = if max_drawdown > 1e-12 { annualized_return / max_drawdown } else { 0.0 };
+
+    let (win_rate, profit_factor, avg_win, avg_loss, trade_count) = trade_stats(positions, bar_returns);
+
+    BacktestResult {
+        equity_curve,
+        total_return,
+        annualized_return,
+        sharpe_ratio,
+        sortino_ratio,
+        calmar_ratio,
+        max_drawdown,
+        max_drawdown_duration,
+        win_rate,
+        profit_factor,
+        avg_win,
+        avg_loss,
+        trade_count,
+    }
+}
+
+/// Tracks a running peak and returns the worst peak-to-trough drop along with
+/// the longest run of bars spent underwater before a new peak was reached.
+fn drawdown_stats(equity_curve: &[f64]) -> (f64, usize) {
+    let mut peak = equity_curve.first().copied().unwrap_or(1.0);
+    let mut max_dd = 0.0;
+    let mut duration = 0usize;
+    let mut max_duration = 0usize;
+    for &e in equity_curve {
+        if e >= peak {
+            peak = e;
+            duration = 0;
+        } else {
+            duration += 1;
+            if duration > max_duration {
+                max_duration = duration;
+            }
+        }
+        let dd = if peak > 0.0 { (peak - e) / peak } else { 0.0 };
+        if dd > max_dd {
+            max_dd = dd;
+        }
+    }
+    (max_dd, max_duration)
+}
+
+/// Accumulates P&L per contiguous same-position segment (a "trade"), closing it
+/// out whenever the position changes — including a direct sign flip with no
+/// intervening flat bar — then derives win rate/profit factor from the
+/// resulting trade P&L distribution.
+fn trade_stats(positions: &[f64], bar_returns: &[f64]) -> (f64, f64, f64, f64, usize) {
+    let mut trade_pnls: Vec<f64> = Vec::new();
+    let mut current_pnl = 0.0;
+    let mut current_position = 0.0;
+
+    for i in 0..bar_returns.len() {
+        let pos = positions[i]; // position held going into bar i+1, matches bar_returns[i]
+        if pos != current_position {
+            if current_position != 0.0 {
+                trade_pnls.push(current_pnl);
+            }
+            current_pnl = 0.0;
+            current_position = pos;
+        }
+        if pos != 0.0 {
+            current_pnl += bar_returns[i];
+        }
+    }
+    if current_position != 0.0 {
+        trade_pnls.push(current_pnl);
+    }
+
+    if trade_pnls.is_empty() {
+        return (0.0, 0.0, 0.0, 0.0, 0);
+    }
+
+    let wins: Vec<f64> = trade_pnls.iter().copied().filter(|p| *p > 0.0).collect();
+    let losses: Vec<f64> = trade_pnls.iter().copied().filter(|p| *p < 0.0).collect();
+
+    let win_rate = wins.len() as f64 / trade_pnls.len() as f64;
+    let gross_win: f64 = wins.iter().sum();
+    let gross_loss: f64 = losses.iter().map(|l| l.abs()).sum();
+    let profit_factor = if gross_loss > 1e-12 { gross_win / gross_loss } else { 0.0 };
+    let avg_win = if wins.is_empty() { 0.0 } else { gross_win / wins.len() as f64 };
+    let avg_loss = if losses.is_empty() { 0.0 } else { losses.iter().sum::<f64>() / losses.len() as f64 };
+
+    (win_rate, profit_factor, avg_win, avg_loss, trade_pnls.len())
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() { return 0.0; }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn stddev(values: &[f64], mean: f64) -> f64 {
+    if values.is_empty() { return 0.0; }
+    (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64).sqrt()
+}
@@ -1,3 +1,10 @@
+// MelowFlame098/Scope#chunk2-5 ("compile the indicator core to WebAssembly in
+// addition to the PyO3 extension") is deferred, not done: it needs a no_std
+// core-crate split and a real wasm-bindgen build target behind a `python`/
+// `wasm` feature split, and this repo has no Cargo.toml/workspace manifest
+// anywhere to declare either a `wasm` feature or the wasm-bindgen/js-sys
+// dependencies on. Revisit once that manifest exists; don't reintroduce
+// feature-gated source for a feature nothing declares.
 use pyo3::prelude::*;
 
 mod s2f;
@@ -8,6 +15,11 @@ mod sopr;
 mod puell;
 mod hash_ribbons;
 mod hodl_waves;
+mod onchain_unified;
+mod spread;
+mod analyzer;
+mod backtest;
+mod signal;
 
 #[pyfunction]
 fn stock_to_flow(stock: Vec<f64>, flow: Vec<f64>, prices: Option<Vec<f64>>) -> PyResult<PyObject> {
@@ -29,6 +41,26 @@ fn stock_to_flow(stock: Vec<f64>, flow: Vec<f64>, prices: Option<Vec<f64>>) -> P
     })
 }
 
+#[pyfunction]
+fn stock_to_flow_isotonic(stock: Vec<f64>, flow: Vec<f64>, prices: Vec<f64>) -> PyResult<PyObject> {
+    let result = s2f::compute_stock_to_flow_isotonic(&stock, &flow, &prices);
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("s2f", result.s2f.clone())?;
+        if let Some(series) = result.predicted_series.clone() {
+            dict.set_item("predicted_series", series)?;
+        }
+        if let Some(r2) = result.r_squared {
+            dict.set_item("r_squared", r2)?;
+        }
+        if let Some(bands) = result.bands.clone() {
+            dict.set_item("upper_band", bands.0)?;
+            dict.set_item("lower_band", bands.1)?;
+        }
+        Ok(dict.into_py(py))
+    })
+}
+
 #[pyfunction]
 fn metcalfe_law(active_addresses: Vec<f64>, prices: Option<Vec<f64>>) -> PyResult<PyObject> {
     let result = metcalfe::compute_metcalfe(&active_addresses, prices.as_ref().map(|v| v.as_slice()));
@@ -54,6 +86,8 @@ fn crypto_log_regression(prices: Vec<f64>, timestamps_days: Option<Vec<f64>>) ->
         dict.set_item("lower_band", result.lower_band)?;
         if let Some(r2) = result.r_squared { dict.set_item("r_squared", r2)?; }
         dict.set_item("coefficients", result.coefficients)?;
+        dict.set_item("std_errors", result.std_errors)?;
+        dict.set_item("lambda", result.lambda)?;
         Ok(dict.into_py(py))
     })
 }
@@ -151,9 +185,153 @@ fn hodl_waves_analyze(dates: Vec<String>, age_days: Vec<f64>, values: Vec<f64>)
     })
 }
 
+#[pyfunction]
+fn onchain_consensus(
+    sopr_series: Vec<f64>,
+    daily_issuance_usd: Vec<f64>,
+    issuance_ma_365: Vec<f64>,
+    dates: Vec<String>,
+    age_days: Vec<f64>,
+    values: Vec<f64>,
+    active_addresses: Vec<f64>,
+    prices: Vec<f64>,
+) -> PyResult<onchain_unified::OnchainConsensus> {
+    let sopr = sopr::compute_sopr(&sopr_series);
+    let puell = puell::compute_puell(&daily_issuance_usd, &issuance_ma_365);
+    let hodl = hodl_waves::compute_hodl_waves(&dates, &age_days, &values);
+    let metcalfe = metcalfe::compute_metcalfe(&active_addresses, Some(&prices));
+    Ok(onchain_unified::compute_onchain_consensus(&sopr, &puell, &hodl, &metcalfe))
+}
+
+#[pyfunction]
+#[pyo3(signature = (high, low, close=None, adjust_overnight_gap=false))]
+fn corwin_schultz_spread(high: Vec<f64>, low: Vec<f64>, close: Option<Vec<f64>>, adjust_overnight_gap: bool) -> PyResult<PyObject> {
+    let result = spread::compute_corwin_schultz_spread(&high, &low, close.as_deref(), adjust_overnight_gap);
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("spread_series", result.spread_series)?;
+        dict.set_item("mean_spread", result.mean_spread)?;
+        dict.set_item("median_spread", result.median_spread)?;
+        Ok(dict.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (market_cap=None, realized_cap=None, sopr=None, issuance=None, issuance_ma_365=None, hash_rate=None, difficulty=None))]
+fn run_analysis(
+    market_cap: Option<Vec<f64>>,
+    realized_cap: Option<Vec<f64>>,
+    sopr: Option<Vec<f64>>,
+    issuance: Option<Vec<f64>>,
+    issuance_ma_365: Option<Vec<f64>>,
+    hash_rate: Option<Vec<f64>>,
+    difficulty: Option<Vec<f64>>,
+) -> PyResult<PyObject> {
+    let result = analyzer::run_analysis(analyzer::AnalyzerInputs {
+        market_cap,
+        realized_cap,
+        sopr,
+        issuance,
+        issuance_ma_365,
+        hash_rate,
+        difficulty,
+    });
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        if let Some(r) = &result.mvrv {
+            let mvrv_dict = pyo3::types::PyDict::new(py);
+            mvrv_dict.set_item("current_mvrv", r.current_mvrv)?;
+            mvrv_dict.set_item("mvrv_z_score", r.mvrv_z_score)?;
+            mvrv_dict.set_item("mvrv_percentile", r.mvrv_percentile)?;
+            mvrv_dict.set_item("market_phase", &r.market_phase)?;
+            dict.set_item("mvrv", mvrv_dict)?;
+        }
+        if let Some(r) = &result.sopr {
+            let sopr_dict = pyo3::types::PyDict::new(py);
+            sopr_dict.set_item("current_sopr", r.current_sopr)?;
+            sopr_dict.set_item("sopr_trend", &r.sopr_trend)?;
+            sopr_dict.set_item("market_sentiment", &r.market_sentiment)?;
+            dict.set_item("sopr", sopr_dict)?;
+        }
+        if let Some(r) = &result.puell {
+            let puell_dict = pyo3::types::PyDict::new(py);
+            puell_dict.set_item("current_puell", r.current_puell)?;
+            puell_dict.set_item("puell_percentile", r.puell_percentile)?;
+            puell_dict.set_item("market_cycle_phase", &r.market_cycle_phase)?;
+            dict.set_item("puell", puell_dict)?;
+        }
+        if let Some(r) = &result.hash_ribbons {
+            let hash_dict = pyo3::types::PyDict::new(py);
+            hash_dict.set_item("hash_ribbon_signal", &r.hash_ribbon_signal)?;
+            hash_dict.set_item("miner_capitulation", r.miner_capitulation)?;
+            hash_dict.set_item("mining_health", &r.mining_health)?;
+            dict.set_item("hash_ribbons", hash_dict)?;
+        }
+        dict.set_item("market_cycle_phase", &result.market_cycle_phase)?;
+        dict.set_item("confidence", result.confidence)?;
+        Ok(dict.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (price, positions, periods_per_year=365.0))]
+fn backtest_signals(price: Vec<f64>, positions: Vec<f64>, periods_per_year: f64) -> PyResult<PyObject> {
+    let result = backtest::backtest_signals(&price, &positions, periods_per_year);
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("equity_curve", result.equity_curve)?;
+        dict.set_item("total_return", result.total_return)?;
+        dict.set_item("annualized_return", result.annualized_return)?;
+        dict.set_item("sharpe_ratio", result.sharpe_ratio)?;
+        dict.set_item("sortino_ratio", result.sortino_ratio)?;
+        dict.set_item("calmar_ratio", result.calmar_ratio)?;
+        dict.set_item("max_drawdown", result.max_drawdown)?;
+        dict.set_item("max_drawdown_duration", result.max_drawdown_duration)?;
+        dict.set_item("win_rate", result.win_rate)?;
+        dict.set_item("profit_factor", result.profit_factor)?;
+        dict.set_item("avg_win", result.avg_win)?;
+        dict.set_item("avg_loss", result.avg_loss)?;
+        dict.set_item("trade_count", result.trade_count)?;
+        Ok(dict.into_py(py))
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (mvrv_percentile, sopr, puell_percentile, hash_ribbon_signal, miner_capitulation, weights=None))]
+fn composite_signal(
+    mvrv_percentile: f64,
+    sopr: f64,
+    puell_percentile: f64,
+    hash_ribbon_signal: String,
+    miner_capitulation: bool,
+    weights: Option<[f64; 4]>,
+) -> PyResult<PyObject> {
+    let result = signal::composite_signal(
+        mvrv_percentile,
+        sopr,
+        puell_percentile,
+        &hash_ribbon_signal,
+        miner_capitulation,
+        weights,
+    );
+    Python::with_gil(|py| {
+        let dict = pyo3::types::PyDict::new(py);
+        dict.set_item("score", result.score)?;
+        dict.set_item("label", result.label)?;
+        let contributions = pyo3::types::PyDict::new(py);
+        contributions.set_item("mvrv", result.mvrv_contribution)?;
+        contributions.set_item("sopr", result.sopr_contribution)?;
+        contributions.set_item("puell", result.puell_contribution)?;
+        contributions.set_item("hash_ribbon", result.hash_ribbon_contribution)?;
+        dict.set_item("contributions", contributions)?;
+        Ok(dict.into_py(py))
+    })
+}
+
 #[pymodule]
 fn crypto_indicators(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(stock_to_flow, m)?)?;
+    m.add_function(wrap_pyfunction!(stock_to_flow_isotonic, m)?)?;
     m.add_function(wrap_pyfunction!(metcalfe_law, m)?)?;
     m.add_function(wrap_pyfunction!(crypto_log_regression, m)?)?;
     m.add_function(wrap_pyfunction!(mvrv_analyze, m)?)?;
@@ -161,5 +339,10 @@ fn crypto_indicators(_py: Python<'_>, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(puell_analyze, m)?)?;
     m.add_function(wrap_pyfunction!(hash_ribbons_analyze, m)?)?;
     m.add_function(wrap_pyfunction!(hodl_waves_analyze, m)?)?;
+    m.add_function(wrap_pyfunction!(onchain_consensus, m)?)?;
+    m.add_function(wrap_pyfunction!(corwin_schultz_spread, m)?)?;
+    m.add_function(wrap_pyfunction!(run_analysis, m)?)?;
+    m.add_function(wrap_pyfunction!(backtest_signals, m)?)?;
+    m.add_function(wrap_pyfunction!(composite_signal, m)?)?;
     Ok(())
 }
\ No newline at end of file
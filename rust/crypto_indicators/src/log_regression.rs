@@ -4,13 +4,23 @@ pub struct LogRegressionResult {
     pub lower_band: Vec<f64>,
     pub r_squared: Option<f64>,
     pub coefficients: Vec<f64>,
+    pub std_errors: Vec<f64>,
+    pub lambda: f64,
 }
 
 // Fit log(price) = a + b*log(t) + c*(t_years)
 pub fn compute_log_regression(prices: &[f64], timestamps_days: Option<&[f64]>) -> LogRegressionResult {
     let n = prices.len();
     if n == 0 {
-        return LogRegressionResult { predicted_series: vec![], upper_band: vec![], lower_band: vec![], r_squared: None, coefficients: vec![0.0,0.0,0.0] };
+        return LogRegressionResult {
+            predicted_series: vec![],
+            upper_band: vec![],
+            lower_band: vec![],
+            r_squared: None,
+            coefficients: vec![0.0, 0.0, 0.0],
+            std_errors: vec![0.0, 0.0, 0.0],
+            lambda: 0.0,
+        };
     }
 
     // Build time vector in days since start
@@ -20,16 +30,16 @@ pub fn compute_log_regression(prices: &[f64], timestamps_days: Option<&[f64]>) -
     };
     let t_years: Vec<f64> = t_days.iter().map(|d| d / 365.25).collect();
 
-    // Design matrix X: [1, ln(t+1), t_years]
-    let mut x0: Vec<f64> = vec![1.0; n];
-    let mut x1: Vec<f64> = t_days.iter().map(|d| (d + 1.0).ln()).collect();
-    let mut x2: Vec<f64> = t_years.clone();
+    // Design matrix columns before standardization: [1, ln(t+1), t_years]
+    let x0: Vec<f64> = vec![1.0; n];
+    let x1: Vec<f64> = t_days.iter().map(|d| (d + 1.0).ln()).collect();
+    let x2: Vec<f64> = t_years.clone();
 
     // Response y = ln(price)
     let y: Vec<f64> = prices.iter().map(|p| if *p > 0.0 { p.ln() } else { 0.0 }).collect();
 
     // Mask valid rows (finite values)
-    let mut x_clean: Vec<[f64;3]> = Vec::with_capacity(n);
+    let mut x_clean: Vec<[f64; 3]> = Vec::with_capacity(n);
     let mut y_clean: Vec<f64> = Vec::with_capacity(n);
     for i in 0..n {
         let xi = [x0[i], x1[i], x2[i]];
@@ -42,14 +52,35 @@ pub fn compute_log_regression(prices: &[f64], timestamps_days: Option<&[f64]>) -
     if x_clean.len() < 10 {
         // Not enough data; return baseline
         let predicted = prices.to_vec();
-        return LogRegressionResult { predicted_series: predicted.clone(), upper_band: predicted.clone(), lower_band: predicted, r_squared: None, coefficients: vec![0.0,0.0,0.0] };
+        return LogRegressionResult {
+            predicted_series: predicted.clone(),
+            upper_band: predicted.clone(),
+            lower_band: predicted,
+            r_squared: None,
+            coefficients: vec![0.0, 0.0, 0.0],
+            std_errors: vec![0.0, 0.0, 0.0],
+            lambda: 0.0,
+        };
     }
 
-    // Compute normal equations: (X^T X) beta = X^T y
-    let mut xtx = [[0.0;3];3];
-    let mut xty = [0.0;3];
-    for i in 0..x_clean.len() {
-        let xi = x_clean[i];
+    // Standardize the non-intercept columns to zero mean/unit variance; the
+    // ln(t+1) and t_years regressors are nearly collinear over long histories,
+    // which makes the raw normal equations ill-conditioned.
+    let (mean1, std1) = mean_std(&x_clean.iter().map(|r| r[1]).collect::<Vec<f64>>());
+    let (mean2, std2) = mean_std(&x_clean.iter().map(|r| r[2]).collect::<Vec<f64>>());
+    let std1 = if std1 > 1e-12 { std1 } else { 1.0 };
+    let std2 = if std2 > 1e-12 { std2 } else { 1.0 };
+
+    let x_std: Vec<[f64; 3]> = x_clean
+        .iter()
+        .map(|r| [1.0, (r[1] - mean1) / std1, (r[2] - mean2) / std2])
+        .collect();
+
+    // Normal equations on the standardized design
+    let mut xtx = [[0.0; 3]; 3];
+    let mut xty = [0.0; 3];
+    for i in 0..x_std.len() {
+        let xi = x_std[i];
         for r in 0..3 {
             for c in 0..3 {
                 xtx[r][c] += xi[r] * xi[c];
@@ -58,31 +89,64 @@ pub fn compute_log_regression(prices: &[f64], timestamps_days: Option<&[f64]>) -
         }
     }
 
-    // Solve 3x3 using Cramer's rule / Gaussian elimination
-    let beta = solve_3x3(xtx, xty);
-    let alpha = beta[0];
-    let b = beta[1];
-    let c = beta[2];
+    // Ridge penalty scaled by the mean diagonal of X^T X, so it stays proportionate
+    // to the data's scale instead of an arbitrary absolute constant.
+    let mean_diag = (xtx[0][0] + xtx[1][1] + xtx[2][2]) / 3.0;
+    let lambda = 1e-6 * mean_diag.max(1e-12);
+    let mut xtx_ridge = xtx;
+    for i in 0..3 {
+        xtx_ridge[i][i] += lambda;
+    }
+
+    let beta_std = solve_3x3(xtx_ridge, xty);
+    let xtx_ridge_inv = invert_3x3(xtx_ridge);
 
-    // Predictions
+    // De-standardize: y = alpha_std + b_std*(x1-m1)/s1 + c_std*(x2-m2)/s2
+    //               = (alpha_std - b_std*m1/s1 - c_std*m2/s2) + (b_std/s1)*x1 + (c_std/s2)*x2
+    let b = beta_std[1] / std1;
+    let c = beta_std[2] / std2;
+    let alpha = beta_std[0] - beta_std[1] * mean1 / std1 - beta_std[2] * mean2 / std2;
+
+    // Predictions over the full (unmasked) series
     let mut log_predicted: Vec<f64> = Vec::with_capacity(n);
     for i in 0..n {
         log_predicted.push(alpha + b * x1[i] + c * x2[i]);
     }
     let predicted_series: Vec<f64> = log_predicted.iter().map(|lp| lp.exp()).collect();
 
-    // Residuals on clean subset
-    let mut residuals: Vec<f64> = Vec::with_capacity(x_clean.len());
-    for i in 0..x_clean.len() {
-        let xi = x_clean[i];
-        let yh = alpha + b * xi[1] + c * xi[2];
+    // Residuals on clean subset, computed in standardized space (same residuals
+    // as original units since de-standardization is an exact reparameterization)
+    let mut residuals: Vec<f64> = Vec::with_capacity(x_std.len());
+    for i in 0..x_std.len() {
+        let xi = x_std[i];
+        let yh = beta_std[0] + beta_std[1] * xi[1] + beta_std[2] * xi[2];
         residuals.push(y_clean[i] - yh);
     }
+    let k = 3.0;
+    let dof = (residuals.len() as f64 - k).max(1.0);
+    let rss: f64 = residuals.iter().map(|r| r * r).sum();
+    let sigma2 = rss / dof;
+
     let mean_res = residuals.iter().sum::<f64>() / (residuals.len() as f64);
     let std_res = (residuals.iter().map(|r| (r - mean_res).powi(2)).sum::<f64>() / (residuals.len() as f64)).sqrt();
     let upper_band: Vec<f64> = log_predicted.iter().map(|lp| (lp + 2.0 * std_res).exp()).collect();
     let lower_band: Vec<f64> = log_predicted.iter().map(|lp| (lp - 2.0 * std_res).exp()).collect();
 
+    // Standard errors in standardized space, rescaled back to original units.
+    // The original-units intercept is a linear combination w·beta_std with
+    // w = [1, -m1/s1, -m2/s2], so its variance is sigma2 * w^T (X^T X + lambda I)^-1 w.
+    let w = [1.0, -mean1 / std1, -mean2 / std2];
+    let mut var_alpha = 0.0;
+    for r in 0..3 {
+        for c in 0..3 {
+            var_alpha += w[r] * w[c] * xtx_ridge_inv[r][c];
+        }
+    }
+    let se_alpha = (sigma2 * var_alpha).max(0.0).sqrt();
+    let se_b_std = (sigma2 * xtx_ridge_inv[1][1]).max(0.0).sqrt();
+    let se_c_std = (sigma2 * xtx_ridge_inv[2][2]).max(0.0).sqrt();
+    let std_errors = vec![se_alpha, se_b_std / std1, se_c_std / std2];
+
     // R-squared
     let ss_res: f64 = residuals.iter().map(|r| r * r).sum();
     let mean_y: f64 = y_clean.iter().sum::<f64>() / (y_clean.len() as f64);
@@ -95,12 +159,21 @@ pub fn compute_log_regression(prices: &[f64], timestamps_days: Option<&[f64]>) -
         lower_band,
         r_squared: Some(r2),
         coefficients: vec![alpha, b, c],
+        std_errors,
+        lambda,
     }
 }
 
-fn solve_3x3(a: [[f64;3];3], b: [f64;3]) -> [f64;3] {
+fn mean_std(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let var = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n;
+    (mean, var.sqrt())
+}
+
+fn solve_3x3(a: [[f64; 3]; 3], b: [f64; 3]) -> [f64; 3] {
     // Gaussian elimination for 3x3
-    let mut m = [[0.0;4];3];
+    let mut m = [[0.0; 4]; 3];
     for i in 0..3 {
         for j in 0..3 { m[i][j] = a[i][j]; }
         m[i][3] = b[i];
@@ -131,4 +204,20 @@ fn solve_3x3(a: [[f64;3];3], b: [f64;3]) -> [f64;3] {
         }
     }
     [m[0][3], m[1][3], m[2][3]]
-}
\ No newline at end of file
+}
+
+/// Inverts a 3x3 matrix by solving for each column of the identity in turn.
+fn invert_3x3(a: [[f64; 3]; 3]) -> [[f64; 3]; 3] {
+    let cols = [
+        solve_3x3(a, [1.0, 0.0, 0.0]),
+        solve_3x3(a, [0.0, 1.0, 0.0]),
+        solve_3x3(a, [0.0, 0.0, 1.0]),
+    ];
+    let mut inv = [[0.0; 3]; 3];
+    for r in 0..3 {
+        for c in 0..3 {
+            inv[r][c] = cols[c][r];
+        }
+    }
+    inv
+}
@@ -3,6 +3,7 @@ pub struct MetcalfeResult {
     pub r_squared: Option<f64>,
     pub alpha: f64,
     pub beta: f64,
+    pub actual_prices: Option<Vec<f64>>,
 }
 
 fn linear_regression(x: &[f64], y: &[f64]) -> (f64, f64, f64) {
@@ -44,6 +45,7 @@ pub fn compute_metcalfe(active_addresses: &[f64], prices: Option<&[f64]>) -> Met
             r_squared: Some(r2),
             alpha,
             beta,
+            actual_prices: Some(p[..m].to_vec()),
         }
     } else {
         // Without prices, return the raw network value using beta=1, alpha=0
@@ -53,6 +55,7 @@ pub fn compute_metcalfe(active_addresses: &[f64], prices: Option<&[f64]>) -> Met
             r_squared: None,
             alpha: 0.0,
             beta: 1.0,
+            actual_prices: None,
         }
     }
 }
\ No newline at end of file
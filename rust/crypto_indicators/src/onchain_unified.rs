@@ -0,0 +1,112 @@
+use pyo3::prelude::*;
+
+use crate::hodl_waves::HODLWavesResult;
+use crate::metcalfe::MetcalfeResult;
+use crate::puell::PuellResult;
+use crate::sopr::SOPRResult;
+
+#[pyclass]
+#[derive(Clone)]
+pub struct OnchainConsensus {
+    #[pyo3(get)]
+    pub cycle_signal: f64,
+    #[pyo3(get)]
+    pub confidence: f64,
+    #[pyo3(get)]
+    pub market_phase: String,
+    #[pyo3(get)]
+    pub sopr_vote: f64,
+    #[pyo3(get)]
+    pub puell_vote: f64,
+    #[pyo3(get)]
+    pub hodl_vote: f64,
+    #[pyo3(get)]
+    pub metcalfe_vote: f64,
+}
+
+/// Fuses the SOPR, Puell, HODL-waves, and Metcalfe verdicts into one cycle read,
+/// mirroring the combined view `unified_analyze` gives on the futures side. Each
+/// metric is mapped to a normalized [-1, +1] cycle score (positive = bullish/
+/// undervalued), averaged into `cycle_signal`, with `confidence` derived from how
+/// tightly the four votes agree (low dispersion = high confidence).
+pub fn compute_onchain_consensus(
+    sopr: &SOPRResult,
+    puell: &PuellResult,
+    hodl: &HODLWavesResult,
+    metcalfe: &MetcalfeResult,
+) -> OnchainConsensus {
+    let sopr_vote = sopr_vote(&sopr.market_sentiment);
+    let puell_vote = ((50.0 - puell.puell_percentile) / 50.0).clamp(-1.0, 1.0);
+    let hodl_vote = hodl_vote(hodl.hodl_strength, &hodl.hodl_trend);
+    let metcalfe_vote = metcalfe_vote(metcalfe);
+
+    let votes = [sopr_vote, puell_vote, hodl_vote, metcalfe_vote];
+    let cycle_signal = (votes.iter().sum::<f64>() / votes.len() as f64).clamp(-1.0, 1.0);
+
+    let mean_vote = votes.iter().sum::<f64>() / votes.len() as f64;
+    let dispersion = (votes.iter().map(|v| (v - mean_vote).powi(2)).sum::<f64>() / votes.len() as f64).sqrt();
+    let confidence = (1.0 - dispersion).clamp(0.0, 1.0);
+
+    let market_phase = determine_phase(cycle_signal);
+
+    OnchainConsensus {
+        cycle_signal,
+        confidence,
+        market_phase,
+        sopr_vote,
+        puell_vote,
+        hodl_vote,
+        metcalfe_vote,
+    }
+}
+
+fn sopr_vote(market_sentiment: &str) -> f64 {
+    match market_sentiment {
+        "Strong Greed - High Profit Taking" => -1.0,
+        "Greed - Moderate Profit Taking" => -0.5,
+        "Neutral - Balanced Market" => 0.0,
+        "Fear - Some Capitulation" => 0.5,
+        "Extreme Fear - Heavy Capitulation" => 1.0,
+        _ => 0.0,
+    }
+}
+
+fn hodl_vote(hodl_strength: f64, hodl_trend: &str) -> f64 {
+    let base = (hodl_strength - 0.5) * 2.0;
+    let trend_adjustment = match hodl_trend {
+        "Strengthening" => 0.1,
+        "Weakening" => -0.1,
+        _ => 0.0,
+    };
+    (base + trend_adjustment).clamp(-1.0, 1.0)
+}
+
+fn metcalfe_vote(metcalfe: &MetcalfeResult) -> f64 {
+    let (actual, predicted) = match (&metcalfe.actual_prices, metcalfe.predicted_series.last()) {
+        (Some(actual), Some(predicted)) => (actual.last(), Some(predicted)),
+        _ => (None, None),
+    };
+    match (actual, predicted) {
+        (Some(a), Some(p)) if *a > 0.0 && *p > 0.0 => {
+            // Positive residual means price trades above the network-value model,
+            // which reads as overvaluation (bearish), and vice versa.
+            let residual = (a / p).ln();
+            (-residual / 0.5).clamp(-1.0, 1.0)
+        }
+        _ => 0.0,
+    }
+}
+
+fn determine_phase(cycle_signal: f64) -> String {
+    if cycle_signal > 0.6 {
+        "Deep Accumulation - Strong Undervaluation".to_string()
+    } else if cycle_signal > 0.2 {
+        "Accumulation - Mild Undervaluation".to_string()
+    } else if cycle_signal > -0.2 {
+        "Neutral - Balanced Cycle".to_string()
+    } else if cycle_signal > -0.6 {
+        "Distribution - Mild Overvaluation".to_string()
+    } else {
+        "Euphoria - Strong Overvaluation".to_string()
+    }
+}
@@ -75,4 +75,125 @@ pub fn compute_stock_to_flow(stock: &[f64], flow: &[f64], prices: Option<&[f64]>
             bands: None,
         }
     }
+}
+
+/// Fits ln(price) as a monotone non-decreasing function of ln(S2F) using the
+/// Pool Adjacent Violators Algorithm, honoring the stock-to-flow theory constraint
+/// that price should not decrease as the S2F ratio rises. PAVA yields pooled
+/// blocks; `predicted_series` interpolates linearly between the blocks' mean-x
+/// knots (held flat beyond the first and last knot). Unlike the unconstrained
+/// OLS path, noisy early data cannot produce a locally downward-sloping fit.
+pub fn compute_stock_to_flow_isotonic(stock: &[f64], flow: &[f64], prices: &[f64]) -> S2FResult {
+    let len = stock.len().min(flow.len()).min(prices.len());
+    let mut s2f = Vec::with_capacity(len);
+    for i in 0..len {
+        let f = flow[i];
+        let ratio = if f.abs() > f64::EPSILON { stock[i] / f } else { 0.0 };
+        s2f.push(ratio.max(0.0));
+    }
+
+    // Pairs (ln S2F, ln price), keeping the original index to map fitted values back.
+    let mut pairs: Vec<(f64, f64, usize)> = Vec::with_capacity(len);
+    for i in 0..len {
+        if s2f[i] > 0.0 && prices[i] > 0.0 {
+            pairs.push((s2f[i].ln(), prices[i].ln(), i));
+        }
+    }
+
+    if pairs.len() < 10 {
+        return S2FResult { s2f, r_squared: None, predicted_series: None, bands: None };
+    }
+
+    pairs.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    let sorted_x: Vec<f64> = pairs.iter().map(|p| p.0).collect();
+    let sorted_y: Vec<f64> = pairs.iter().map(|p| p.1).collect();
+
+    let blocks = pava(&sorted_x, &sorted_y);
+
+    // Knot points for interpolation: each block's mean x and pooled value.
+    let knots: Vec<(f64, f64)> = blocks.iter().map(|b| (b.mean_x, b.value)).collect();
+
+    let mut predicted_series = vec![0.0; len];
+    let mut price_residuals: Vec<f64> = Vec::with_capacity(len);
+    let mut residuals_ln: Vec<f64> = Vec::with_capacity(len);
+    let mut y_ln: Vec<f64> = Vec::with_capacity(len);
+    for i in 0..len {
+        if s2f[i] > 0.0 {
+            let fitted_ln = interpolate(&knots, s2f[i].ln());
+            predicted_series[i] = fitted_ln.exp();
+            if prices[i] > 0.0 {
+                residuals_ln.push(prices[i].ln() - fitted_ln);
+                price_residuals.push(prices[i] - predicted_series[i]);
+                y_ln.push(prices[i].ln());
+            }
+        }
+    }
+
+    // Bands are derived from residuals in price space, exactly as the OLS path
+    // does, rather than in log space: std_res is additive here, not multiplicative.
+    let mean_res = price_residuals.iter().sum::<f64>() / price_residuals.len().max(1) as f64;
+    let std_res = (price_residuals.iter().map(|r| (r - mean_res).powi(2)).sum::<f64>() / price_residuals.len().max(1) as f64).sqrt();
+    let upper_band: Vec<f64> = predicted_series.iter().map(|v| v + std_res).collect();
+    let lower_band: Vec<f64> = predicted_series.iter().map(|v| (v - std_res).max(0.0)).collect();
+
+    let ss_res: f64 = residuals_ln.iter().map(|r| r * r).sum();
+    let mean_y: f64 = y_ln.iter().sum::<f64>() / y_ln.len().max(1) as f64;
+    let ss_tot: f64 = y_ln.iter().map(|yi| (yi - mean_y).powi(2)).sum();
+    let r_squared = if ss_tot > 0.0 { Some(1.0 - (ss_res / ss_tot)) } else { Some(0.0) };
+
+    S2FResult {
+        s2f,
+        r_squared,
+        predicted_series: Some(predicted_series),
+        bands: Some((upper_band, lower_band)),
+    }
+}
+
+struct PavaBlock {
+    mean_x: f64,
+    value: f64,
+    weight: f64,
+}
+
+/// Pool Adjacent Violators Algorithm: given x-sorted (x, y) pairs, returns the
+/// minimal set of pooled blocks whose values are non-decreasing in x.
+fn pava(sorted_x: &[f64], sorted_y: &[f64]) -> Vec<PavaBlock> {
+    let mut blocks: Vec<PavaBlock> = Vec::with_capacity(sorted_y.len());
+    for i in 0..sorted_y.len() {
+        blocks.push(PavaBlock { mean_x: sorted_x[i], value: sorted_y[i], weight: 1.0 });
+        while blocks.len() > 1 && blocks[blocks.len() - 2].value > blocks[blocks.len() - 1].value {
+            let last = blocks.pop().unwrap();
+            let prev = blocks.pop().unwrap();
+            let weight = prev.weight + last.weight;
+            let value = (prev.value * prev.weight + last.value * last.weight) / weight;
+            let mean_x = (prev.mean_x * prev.weight + last.mean_x * last.weight) / weight;
+            blocks.push(PavaBlock { mean_x, value, weight });
+        }
+    }
+    blocks
+}
+
+/// Piecewise-linear interpolation over monotone (x, y) knots, clamped outside range.
+fn interpolate(knots: &[(f64, f64)], x: f64) -> f64 {
+    if knots.is_empty() {
+        return 0.0;
+    }
+    if x <= knots[0].0 {
+        return knots[0].1;
+    }
+    if x >= knots[knots.len() - 1].0 {
+        return knots[knots.len() - 1].1;
+    }
+    for w in knots.windows(2) {
+        let (x0, y0) = w[0];
+        let (x1, y1) = w[1];
+        if x >= x0 && x <= x1 {
+            if (x1 - x0).abs() < 1e-12 {
+                return y0;
+            }
+            let frac = (x - x0) / (x1 - x0);
+            return y0 + frac * (y1 - y0);
+        }
+    }
+    knots[knots.len() - 1].1
 }
\ No newline at end of file
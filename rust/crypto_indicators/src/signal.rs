@@ -0,0 +1,73 @@
+#[derive(Clone, Debug)]
+pub struct CompositeSignalResult {
+    pub score: f64,
+    pub label: String,
+    pub mvrv_contribution: f64,
+    pub sopr_contribution: f64,
+    pub puell_contribution: f64,
+    pub hash_ribbon_contribution: f64,
+}
+
+/// Weighted market-cycle signal engine: maps MVRV, SOPR, Puell, and Hash-Ribbon
+/// outputs to normalized [-1, +1] sub-votes (positive = bullish), combines them
+/// with a user-supplied weight vector (`[mvrv, sopr, puell, hash_ribbon]`,
+/// defaulting to equal weights), and reduces the weighted mean to a discrete
+/// Strong Sell..Strong Buy label. `mvrv_contribution` etc. are the weighted
+/// per-indicator terms, so callers can see how much each metric moved the score.
+pub fn composite_signal(
+    mvrv_percentile: f64,
+    sopr: f64,
+    puell_percentile: f64,
+    hash_ribbon_signal: &str,
+    miner_capitulation: bool,
+    weights: Option<[f64; 4]>,
+) -> CompositeSignalResult {
+    let weights = weights.unwrap_or([0.25, 0.25, 0.25, 0.25]);
+
+    let mvrv_vote = ((50.0 - mvrv_percentile) / 50.0).clamp(-1.0, 1.0);
+    let sopr_vote = ((1.0 - sopr) / 0.1).clamp(-1.0, 1.0);
+    let puell_vote = ((50.0 - puell_percentile) / 50.0).clamp(-1.0, 1.0);
+    let hash_ribbon_vote = hash_ribbon_vote(hash_ribbon_signal, miner_capitulation);
+
+    let votes = [mvrv_vote, sopr_vote, puell_vote, hash_ribbon_vote];
+    let weight_sum: f64 = weights.iter().sum();
+    let contributions: Vec<f64> = if weight_sum.abs() > 1e-12 {
+        votes.iter().zip(weights.iter()).map(|(v, w)| v * w / weight_sum).collect()
+    } else {
+        vec![0.0; 4]
+    };
+
+    let score = contributions.iter().sum::<f64>().clamp(-1.0, 1.0);
+    let label = determine_label(score);
+
+    CompositeSignalResult {
+        score,
+        label,
+        mvrv_contribution: contributions[0],
+        sopr_contribution: contributions[1],
+        puell_contribution: contributions[2],
+        hash_ribbon_contribution: contributions[3],
+    }
+}
+
+fn hash_ribbon_vote(hash_ribbon_signal: &str, miner_capitulation: bool) -> f64 {
+    let base = if hash_ribbon_signal.contains("Buy") { 0.5 } else { -0.5 };
+    // Capitulation is a stress event, but historically precedes a miner-driven
+    // bottom, so it nudges the vote bullish rather than compounding the bearishness.
+    let adjustment = if miner_capitulation { 0.3 } else { 0.0 };
+    (base + adjustment).clamp(-1.0, 1.0)
+}
+
+fn determine_label(score: f64) -> String {
+    if score > 0.6 {
+        "Strong Buy".to_string()
+    } else if score > 0.2 {
+        "Buy".to_string()
+    } else if score > -0.2 {
+        "Neutral".to_string()
+    } else if score > -0.6 {
+        "Sell".to_string()
+    } else {
+        "Strong Sell".to_string()
+    }
+}
@@ -0,0 +1,75 @@
+#[derive(Clone, Debug)]
+pub struct SpreadResult {
+    pub spread_series: Vec<f64>,
+    pub mean_spread: f64,
+    pub median_spread: f64,
+}
+
+const CORWIN_SCHULTZ_K: f64 = 3.0 - 2.0 * std::f64::consts::SQRT_2;
+
+/// Corwin-Schultz (2012) high-low effective spread estimator: for each pair of
+/// consecutive bars, beta is the sum of squared log high/low ranges and gamma is
+/// the squared log range of the two-bar window, combined into alpha and mapped to
+/// a spread in [0, 1]. When `adjust_overnight_gap` is set, the second bar's high/low
+/// are shifted so a close-to-open gap isn't mistaken for intrabar range. The series
+/// is front-padded with zero for the first bar, which has no prior bar to pair with.
+pub fn compute_corwin_schultz_spread(
+    high: &[f64],
+    low: &[f64],
+    close: Option<&[f64]>,
+    adjust_overnight_gap: bool,
+) -> SpreadResult {
+    let n = high.len().min(low.len());
+    let mut spread_series = vec![0.0; n];
+
+    for i in 1..n {
+        let (mut h1, mut l1) = (high[i], low[i]);
+        if adjust_overnight_gap {
+            if let Some(c) = close {
+                let prev_close = c[i - 1];
+                let gap = (prev_close - h1).max(0.0) + (prev_close - l1).min(0.0);
+                h1 += gap;
+                l1 += gap;
+            }
+        }
+
+        if h1 <= 0.0 || l1 <= 0.0 || high[i - 1] <= 0.0 || low[i - 1] <= 0.0 {
+            continue;
+        }
+
+        let beta = (h1 / l1).ln().powi(2) + (high[i - 1] / low[i - 1]).ln().powi(2);
+        let h_max = h1.max(high[i - 1]);
+        let l_min = l1.min(low[i - 1]);
+        let gamma = (h_max / l_min).ln().powi(2);
+
+        let alpha = ((2.0 * beta).sqrt() - beta.sqrt()) / CORWIN_SCHULTZ_K - (gamma / CORWIN_SCHULTZ_K).sqrt();
+        let alpha = alpha.max(0.0);
+        let spread = 2.0 * (alpha.exp() - 1.0) / (1.0 + alpha.exp());
+        spread_series[i] = spread.max(0.0);
+    }
+
+    let mean_spread = mean(&spread_series[1.min(n)..]);
+    let median_spread = median(&spread_series[1.min(n)..]);
+
+    SpreadResult {
+        spread_series,
+        mean_spread,
+        median_spread,
+    }
+}
+
+fn mean(series: &[f64]) -> f64 {
+    if series.is_empty() { 0.0 } else { series.iter().sum::<f64>() / series.len() as f64 }
+}
+
+fn median(series: &[f64]) -> f64 {
+    if series.is_empty() { return 0.0; }
+    let mut sorted = series.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+    let mid = sorted.len() / 2;
+    if sorted.len() % 2 == 0 {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
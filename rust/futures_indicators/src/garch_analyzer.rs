@@ -0,0 +1,138 @@
+use pyo3::prelude::*;
+
+/// Result of fitting a GARCH(1,1) conditional-volatility model.
+#[pyclass]
+#[derive(Clone)]
+pub struct GarchResult {
+    #[pyo3(get)]
+    pub omega: f64,
+    #[pyo3(get)]
+    pub alpha: f64,
+    #[pyo3(get)]
+    pub beta: f64,
+    #[pyo3(get)]
+    pub persistence: f64,
+    #[pyo3(get)]
+    pub long_run_variance: f64,
+    #[pyo3(get)]
+    pub conditional_variance: Vec<f64>,
+    #[pyo3(get)]
+    pub forecast: Vec<f64>,
+}
+
+/// Fits σ²_t = ω + α·ε²_{t-1} + β·σ²_{t-1} to mean-centered `returns` by maximizing
+/// the Gaussian log-likelihood over a bounded grid-refinement search on the
+/// simplex ω>0, α≥0, β≥0, α+β<1 (the crate has no general-purpose optimizer).
+/// Returns the fitted conditional-variance path and an `horizon`-step-ahead
+/// variance forecast converging to the long-run variance.
+pub fn fit_garch11(returns: &[f64], horizon: usize) -> GarchResult {
+    let n = returns.len();
+    if n < 10 {
+        let sample_var = variance(returns);
+        return GarchResult {
+            omega: sample_var,
+            alpha: 0.0,
+            beta: 0.0,
+            persistence: 0.0,
+            long_run_variance: sample_var,
+            conditional_variance: vec![sample_var; n],
+            forecast: vec![sample_var; horizon],
+        };
+    }
+
+    let mean = returns.iter().sum::<f64>() / n as f64;
+    let eps: Vec<f64> = returns.iter().map(|r| r - mean).collect();
+    let sample_var = variance(returns).max(1e-12);
+
+    // Start from the unconditional variance and a conventional persistent-but-stable guess.
+    let mut alpha = 0.1;
+    let mut beta = 0.85;
+    let mut omega = sample_var * (1.0 - alpha - beta).max(1e-6);
+
+    let mut alpha_step = 0.05;
+    let mut beta_step = 0.05;
+    let mut omega_step = omega.max(1e-8) * 0.5;
+
+    let mut best_ll = gaussian_log_lik(&eps, omega, alpha, beta, sample_var);
+    for _ in 0..6 {
+        for _ in 0..8 {
+            let mut improved = false;
+            for (d_omega, d_alpha, d_beta) in [
+                (omega_step, 0.0, 0.0), (-omega_step, 0.0, 0.0),
+                (0.0, alpha_step, 0.0), (0.0, -alpha_step, 0.0),
+                (0.0, 0.0, beta_step), (0.0, 0.0, -beta_step),
+            ] {
+                let cand_omega = omega + d_omega;
+                let cand_alpha = alpha + d_alpha;
+                let cand_beta = beta + d_beta;
+                if cand_omega <= 0.0 || cand_alpha < 0.0 || cand_beta < 0.0 || cand_alpha + cand_beta >= 1.0 {
+                    continue;
+                }
+                let ll = gaussian_log_lik(&eps, cand_omega, cand_alpha, cand_beta, sample_var);
+                if ll > best_ll {
+                    best_ll = ll;
+                    omega = cand_omega;
+                    alpha = cand_alpha;
+                    beta = cand_beta;
+                    improved = true;
+                }
+            }
+            if !improved {
+                break;
+            }
+        }
+        omega_step *= 0.5;
+        alpha_step *= 0.5;
+        beta_step *= 0.5;
+    }
+
+    let conditional_variance = conditional_variance_path(&eps, omega, alpha, beta, sample_var);
+    let persistence = alpha + beta;
+    let long_run_variance = if persistence < 1.0 { omega / (1.0 - persistence) } else { sample_var };
+
+    let last_var = conditional_variance.last().copied().unwrap_or(sample_var);
+    let mut forecast = Vec::with_capacity(horizon);
+    for k in 1..=horizon {
+        let decay = persistence.powi(k as i32);
+        forecast.push(long_run_variance + decay * (last_var - long_run_variance));
+    }
+
+    GarchResult {
+        omega,
+        alpha,
+        beta,
+        persistence,
+        long_run_variance,
+        conditional_variance,
+        forecast,
+    }
+}
+
+fn conditional_variance_path(eps: &[f64], omega: f64, alpha: f64, beta: f64, seed_var: f64) -> Vec<f64> {
+    let n = eps.len();
+    let mut sigma2 = vec![0.0; n];
+    sigma2[0] = seed_var;
+    for t in 1..n {
+        sigma2[t] = omega + alpha * eps[t - 1].powi(2) + beta * sigma2[t - 1];
+    }
+    sigma2
+}
+
+fn gaussian_log_lik(eps: &[f64], omega: f64, alpha: f64, beta: f64, seed_var: f64) -> f64 {
+    let sigma2 = conditional_variance_path(eps, omega, alpha, beta, seed_var);
+    let mut ll = 0.0;
+    for t in 0..eps.len() {
+        let s2 = sigma2[t].max(1e-12);
+        ll += -0.5 * (s2.ln() + eps[t].powi(2) / s2);
+    }
+    ll
+}
+
+fn variance(values: &[f64]) -> f64 {
+    let n = values.len();
+    if n == 0 {
+        return 0.0;
+    }
+    let mean = values.iter().sum::<f64>() / n as f64;
+    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / n as f64
+}
@@ -4,6 +4,9 @@ mod momentum;
 mod mean_reversion;
 mod samuelson;
 mod unified;
+mod signal;
+mod garch_analyzer;
+mod perf;
 
 /// Python module: futures_indicators
 #[pymodule]
@@ -21,6 +24,24 @@ fn futures_indicators(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(analyze_mean_reversion, m)?)?;
     m.add_function(wrap_pyfunction!(analyze_samuelson, m)?)?;
     m.add_function(wrap_pyfunction!(unified_analyze, m)?)?;
+
+    // Expose trend-strength/volatility indicators and the composite signal engine
+    m.add_function(wrap_pyfunction!(atr, m)?)?;
+    m.add_function(wrap_pyfunction!(adx, m)?)?;
+    m.add_function(wrap_pyfunction!(composite_signal, m)?)?;
+
+    // Expose the liquidity/transaction-cost estimator
+    m.add_function(wrap_pyfunction!(corwin_schultz_spread, m)?)?;
+    m.add_function(wrap_pyfunction!(corwin_schultz, m)?)?;
+
+    // Expose the GARCH(1,1) conditional-volatility model
+    m.add_function(wrap_pyfunction!(fit_garch11, m)?)?;
+
+    // Expose the backtest performance-statistics subsystem
+    m.add_function(wrap_pyfunction!(evaluate, m)?)?;
+
+    // Expose the cross-sectional RSI ranking API
+    m.add_function(wrap_pyfunction!(rsi_rank, m)?)?;
     Ok(())
 }
 
@@ -63,6 +84,79 @@ fn analyze_samuelson(close: Vec<f64>, basis: Option<Vec<f64>>) -> PyResult<samue
 }
 
 #[pyfunction]
-fn unified_analyze(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>, volume: Vec<f64>, basis: Option<Vec<f64>>) -> PyResult<unified::FuturesUnifiedResult> {
-    unified::unified_analyze(high, low, close, volume, basis)
+#[pyo3(signature = (high, low, close, volume, basis=None, atr_multiple=2.0, reward_multiple=1.5))]
+fn unified_analyze(
+    high: Vec<f64>,
+    low: Vec<f64>,
+    close: Vec<f64>,
+    volume: Vec<f64>,
+    basis: Option<Vec<f64>>,
+    atr_multiple: f64,
+    reward_multiple: f64,
+) -> PyResult<unified::FuturesUnifiedResult> {
+    unified::unified_analyze(high, low, close, volume, basis, Some(atr_multiple), Some(reward_multiple))
+}
+
+#[pyfunction]
+fn atr(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>, period: Option<usize>) -> PyResult<Vec<f64>> {
+    Ok(tech::atr(&high, &low, &close, period.unwrap_or(14)))
+}
+
+#[pyfunction]
+fn adx(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>, period: Option<usize>) -> PyResult<(Vec<f64>, Vec<f64>, Vec<f64>)> {
+    Ok(tech::adx(&high, &low, &close, period.unwrap_or(14)))
+}
+
+#[pyfunction]
+#[pyo3(signature = (high, low, close, fast_period=10, slow_period=30, rsi_period=14, adx_period=14, adx_threshold=20.0, atr_period=14, atr_multiplier=2.0))]
+fn composite_signal(
+    high: Vec<f64>,
+    low: Vec<f64>,
+    close: Vec<f64>,
+    fast_period: usize,
+    slow_period: usize,
+    rsi_period: usize,
+    adx_period: usize,
+    adx_threshold: f64,
+    atr_period: usize,
+    atr_multiplier: f64,
+) -> PyResult<signal::CompositeSignalResult> {
+    Ok(signal::composite_signal(
+        &high,
+        &low,
+        &close,
+        fast_period,
+        slow_period,
+        rsi_period,
+        adx_period,
+        adx_threshold,
+        atr_period,
+        atr_multiplier,
+    ))
+}
+
+#[pyfunction]
+fn corwin_schultz_spread(high: Vec<f64>, low: Vec<f64>, close: Option<Vec<f64>>, period: Option<usize>) -> PyResult<Vec<f64>> {
+    Ok(tech::corwin_schultz_spread(&high, &low, close.as_deref(), period.unwrap_or(1)))
+}
+
+#[pyfunction]
+fn corwin_schultz(high: Vec<f64>, low: Vec<f64>, close: Option<Vec<f64>>) -> PyResult<Vec<f64>> {
+    Ok(tech::corwin_schultz(&high, &low, close.as_deref()))
+}
+
+#[pyfunction]
+fn fit_garch11(returns: Vec<f64>, horizon: Option<usize>) -> PyResult<garch_analyzer::GarchResult> {
+    Ok(garch_analyzer::fit_garch11(&returns, horizon.unwrap_or(10)))
+}
+
+#[pyfunction]
+#[pyo3(signature = (close, signals, periods_per_year=252.0, fee_bps=0.0))]
+fn evaluate(close: Vec<f64>, signals: Vec<String>, periods_per_year: f64, fee_bps: f64) -> PyResult<perf::PerfStats> {
+    Ok(perf::evaluate(&close, &signals, periods_per_year, fee_bps))
+}
+
+#[pyfunction]
+fn rsi_rank(closes: Vec<Vec<f64>>, period: Option<usize>, top_n: usize) -> PyResult<(Vec<usize>, Vec<usize>)> {
+    Ok(tech::rsi_rank(&closes, period.unwrap_or(14), top_n))
 }
\ No newline at end of file
@@ -16,8 +16,14 @@ pub struct MeanReversionResult {
     #[pyo3(get)]
     pub z_scores: Vec<f64>,
     #[pyo3(get)]
+    pub adf_statistic: f64,
+    #[pyo3(get)]
     pub adf_pvalue: f64,
     #[pyo3(get)]
+    pub adf_lag: usize,
+    #[pyo3(get)]
+    pub is_stationary: bool,
+    #[pyo3(get)]
     pub half_life: f64,
     #[pyo3(get)]
     pub reversion_probability: Vec<f64>,
@@ -37,7 +43,7 @@ pub fn analyze_mean_reversion(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>) ->
     let reversion_probability = z_scores.iter().map(|z| (-(z.abs())).exp().clamp(0.0, 1.0)).collect();
     let oversold_levels: Vec<bool> = z_scores.iter().map(|z| *z < -2.0).collect();
     let overbought_levels: Vec<bool> = z_scores.iter().map(|z| *z > 2.0).collect();
-    let adf_pvalue = 0.5; // Placeholder; requires statsmodels equivalent
+    let adf = adf_test(&close, false);
 
     Ok(MeanReversionResult {
         mean_reversion_scores,
@@ -46,7 +52,10 @@ pub fn analyze_mean_reversion(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>) ->
         bollinger_lower: bb_lower,
         bollinger_middle: bb_middle,
         z_scores,
-        adf_pvalue,
+        adf_statistic: adf.statistic,
+        adf_pvalue: adf.p_value,
+        adf_lag: adf.lag,
+        is_stationary: adf.is_stationary,
         half_life,
         reversion_probability,
         oversold_levels,
@@ -54,6 +63,197 @@ pub fn analyze_mean_reversion(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>) ->
     })
 }
 
+/// Result of an Augmented Dickey-Fuller unit-root test.
+struct AdfResult {
+    statistic: f64,
+    p_value: f64,
+    lag: usize,
+    is_stationary: bool,
+}
+
+/// Augmented Dickey-Fuller test for a unit root in `series`.
+///
+/// Regresses Δy_t = α + γ·y_{t-1} + Σ δ_i·Δy_{t-i} + ε_t (optionally with a linear
+/// trend term), picking the lag order p in [0, max_lag] that minimizes AIC, as in
+/// the standard `12·(n/100)^0.25` rule. The test statistic is the t-ratio on γ̂.
+fn adf_test(series: &[f64], with_trend: bool) -> AdfResult {
+    let n_obs = series.len();
+    if n_obs < 20 {
+        return AdfResult { statistic: 0.0, p_value: 1.0, lag: 0, is_stationary: false };
+    }
+
+    let dy: Vec<f64> = (1..n_obs).map(|i| series[i] - series[i - 1]).collect();
+    let max_lag = (12.0 * (n_obs as f64 / 100.0).powf(0.25)).floor() as usize;
+    let max_lag = max_lag.min(dy.len().saturating_sub(2));
+
+    let mut best: Option<(f64, AdfResult)> = None;
+    for p in 0..=max_lag {
+        if let Some((tau, rows)) = fit_adf_regression(series, &dy, p, max_lag, with_trend) {
+            let k = rows.k as f64;
+            let aic = rows.n as f64 * (rows.rss / rows.n as f64).ln() + 2.0 * k;
+            let p_value = adf_pvalue(tau, with_trend);
+            let candidate = AdfResult {
+                statistic: tau,
+                p_value,
+                lag: p,
+                is_stationary: p_value < 0.05,
+            };
+            if best.as_ref().map_or(true, |(best_aic, _)| aic < *best_aic) {
+                best = Some((aic, candidate));
+            }
+        }
+    }
+
+    best.map(|(_, r)| r).unwrap_or(AdfResult { statistic: 0.0, p_value: 1.0, lag: 0, is_stationary: false })
+}
+
+struct RegressionFit {
+    n: usize,
+    k: usize,
+    rss: f64,
+}
+
+/// Builds the ADF design matrix for lag order `p` and returns (tau, fit stats) if
+/// there are enough observations to estimate it.
+fn fit_adf_regression(level: &[f64], dy: &[f64], p: usize, max_lag: usize, with_trend: bool) -> Option<(f64, RegressionFit)> {
+    // Row i (0-indexed into dy) uses dy[i] as the response, requiring lags dy[i-1..i-p]
+    // and level y_{t-1} = level[i]. Rows always start at i = max_lag, not i = p, so every
+    // candidate lag order is fit on the same n observations and AIC is comparable across p.
+    let n = dy.len().saturating_sub(max_lag);
+    let k = 2 + p + if with_trend { 1 } else { 0 }; // const, gamma, p lags, optional trend
+    if n <= k + 1 {
+        return None;
+    }
+
+    let mut x: Vec<Vec<f64>> = Vec::with_capacity(n);
+    let mut y: Vec<f64> = Vec::with_capacity(n);
+    for row in 0..n {
+        let i = row + max_lag;
+        let mut xi = vec![1.0, level[i]];
+        for lag in 1..=p {
+            xi.push(dy[i - lag]);
+        }
+        if with_trend {
+            xi.push(i as f64);
+        }
+        x.push(xi);
+        y.push(dy[i]);
+    }
+
+    let xtx = gram_matrix(&x, k);
+    let xty = gram_vector(&x, &y, k);
+    let xtx_inv = invert_matrix(&xtx)?;
+    let beta = mat_vec_mul(&xtx_inv, &xty);
+
+    let mut rss = 0.0;
+    for row in 0..n {
+        let y_hat: f64 = (0..k).map(|j| x[row][j] * beta[j]).sum();
+        rss += (y[row] - y_hat).powi(2);
+    }
+    let sigma2 = rss / (n as f64 - k as f64);
+    let se_gamma = (sigma2 * xtx_inv[1][1]).max(0.0).sqrt();
+    let tau = if se_gamma <= 1e-12 { 0.0 } else { beta[1] / se_gamma };
+
+    Some((tau, RegressionFit { n, k, rss }))
+}
+
+fn gram_matrix(x: &[Vec<f64>], k: usize) -> Vec<Vec<f64>> {
+    let mut m = vec![vec![0.0; k]; k];
+    for xi in x {
+        for r in 0..k {
+            for c in 0..k {
+                m[r][c] += xi[r] * xi[c];
+            }
+        }
+    }
+    m
+}
+
+fn gram_vector(x: &[Vec<f64>], y: &[f64], k: usize) -> Vec<f64> {
+    let mut v = vec![0.0; k];
+    for (xi, yi) in x.iter().zip(y.iter()) {
+        for r in 0..k {
+            v[r] += xi[r] * yi;
+        }
+    }
+    v
+}
+
+/// Inverts an n×n matrix via Gauss-Jordan elimination with partial pivoting.
+fn invert_matrix(a: &[Vec<f64>]) -> Option<Vec<Vec<f64>>> {
+    let n = a.len();
+    let mut m: Vec<Vec<f64>> = a.iter().enumerate().map(|(i, row)| {
+        let mut r = row.clone();
+        r.extend(vec![0.0; n]);
+        r[n + i] = 1.0;
+        r
+    }).collect();
+
+    for col in 0..n {
+        let mut pivot = col;
+        for r in col + 1..n {
+            if m[r][col].abs() > m[pivot][col].abs() { pivot = r; }
+        }
+        if m[pivot][col].abs() < 1e-12 {
+            return None;
+        }
+        m.swap(col, pivot);
+        let div = m[col][col];
+        for j in 0..2 * n {
+            m[col][j] /= div;
+        }
+        for r in 0..n {
+            if r == col { continue; }
+            let factor = m[r][col];
+            if factor == 0.0 { continue; }
+            for j in 0..2 * n {
+                m[r][j] -= factor * m[col][j];
+            }
+        }
+    }
+
+    Some(m.into_iter().map(|row| row[n..].to_vec()).collect())
+}
+
+fn mat_vec_mul(a: &[Vec<f64>], v: &[f64]) -> Vec<f64> {
+    a.iter().map(|row| row.iter().zip(v.iter()).map(|(ai, vi)| ai * vi).sum()).collect()
+}
+
+/// Approximates a left-tail ADF p-value by interpolating over MacKinnon's tabulated
+/// asymptotic critical values for the "constant, no trend" and "constant plus trend"
+/// cases, extrapolating linearly from the outermost pair of table points into the
+/// tails, with the result clamped to [0, 1].
+fn adf_pvalue(tau: f64, with_trend: bool) -> f64 {
+    // (quantile, critical value) pairs, asymptotic, from MacKinnon (1994) Table 1.
+    let table: &[(f64, f64)] = if with_trend {
+        &[(0.01, -3.96), (0.025, -3.66), (0.05, -3.41), (0.10, -3.12), (0.50, -1.86), (0.90, -0.58), (0.95, -0.23), (0.99, 0.47)]
+    } else {
+        &[(0.01, -3.43), (0.025, -3.12), (0.05, -2.86), (0.10, -2.57), (0.50, -1.22), (0.90, 0.13), (0.95, 0.49), (0.99, 1.28)]
+    };
+
+    if tau <= table[0].1 {
+        let (p_lo, tau_lo) = table[0];
+        let (p_hi, tau_hi) = table[1];
+        let slope = (p_hi - p_lo) / (tau_hi - tau_lo);
+        return (p_lo + slope * (tau - tau_lo)).clamp(0.0, 1.0);
+    }
+    if tau >= table[table.len() - 1].1 {
+        let (p_hi, tau_hi) = table[table.len() - 1];
+        let (p_lo, tau_lo) = table[table.len() - 2];
+        let slope = (p_hi - p_lo) / (tau_hi - tau_lo);
+        return (p_hi + slope * (tau - tau_hi)).clamp(0.0, 1.0);
+    }
+    for w in table.windows(2) {
+        let (p_lo, tau_lo) = w[0];
+        let (p_hi, tau_hi) = w[1];
+        if tau >= tau_lo && tau <= tau_hi {
+            let frac = (tau - tau_lo) / (tau_hi - tau_lo);
+            return (p_lo + frac * (p_hi - p_lo)).clamp(0.0, 1.0);
+        }
+    }
+    0.5
+}
+
 fn rolling_mean(data: &[f64], window: usize) -> Vec<f64> {
     let n = data.len();
     let mut out = vec![0.0; n];
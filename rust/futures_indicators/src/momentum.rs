@@ -27,6 +27,14 @@ pub struct MomentumAnalysis {
     pub stochastic_d: Vec<f64>,
     #[pyo3(get)]
     pub williams_r: Vec<f64>,
+    #[pyo3(get)]
+    pub adx: Vec<f64>,
+    #[pyo3(get)]
+    pub plus_di: Vec<f64>,
+    #[pyo3(get)]
+    pub minus_di: Vec<f64>,
+    #[pyo3(get)]
+    pub trend_confirmed: Vec<bool>,
 }
 
 pub fn analyze(high: &[f64], low: &[f64], close: &[f64], volume: &[f64]) -> MomentumAnalysis {
@@ -34,11 +42,13 @@ pub fn analyze(high: &[f64], low: &[f64], close: &[f64], volume: &[f64]) -> Mome
     let (macd_values, macd_signal) = tech::macd(close);
     let (stochastic_k, stochastic_d) = tech::stochastic(high, low, close, 14);
     let williams_r = tech::williams_r(high, low, close, 14);
+    let (adx, plus_di, minus_di) = tech::adx(high, low, close, 14);
+    let trend_confirmed: Vec<bool> = adx.iter().map(|a| *a > 25.0).collect();
 
     let momentum_scores = composite_scores(&rsi_values, &macd_values, &macd_signal, &stochastic_k, &williams_r);
     let momentum_signals = generate_signals(&momentum_scores);
     let momentum_strength = calculate_strength(&momentum_scores, volume);
-    let trend_direction = determine_trend(close, &macd_values);
+    let trend_direction = determine_trend(close, &macd_values, &trend_confirmed);
     let momentum_divergence = detect_divergence(close, &rsi_values);
 
     MomentumAnalysis {
@@ -53,6 +63,10 @@ pub fn analyze(high: &[f64], low: &[f64], close: &[f64], volume: &[f64]) -> Mome
         stochastic_k,
         stochastic_d,
         williams_r,
+        adx,
+        plus_di,
+        minus_di,
+        trend_confirmed,
     }
 }
 
@@ -97,12 +111,22 @@ fn calculate_strength(scores: &[f64], volume: &[f64]) -> Vec<f64> {
     out
 }
 
-fn determine_trend(close: &[f64], macd: &[f64]) -> Vec<String> {
-    let n = close.len().min(macd.len());
+fn determine_trend(close: &[f64], macd: &[f64], trend_confirmed: &[bool]) -> Vec<String> {
+    let n = close.len().min(macd.len()).min(trend_confirmed.len());
     let mut out = Vec::with_capacity(n);
     for i in 0..n {
         let slope = if i == 0 { 0.0 } else { macd[i] - macd[i - 1] };
-        let trend = if slope > 0.0 { "UP" } else if slope < 0.0 { "DOWN" } else { "SIDEWAYS" };
+        // Only trust MACD slope as a directional call when ADX confirms a real
+        // trend is in force; otherwise chop produces false UP/DOWN flips.
+        let trend = if !trend_confirmed[i] {
+            "SIDEWAYS"
+        } else if slope > 0.0 {
+            "UP"
+        } else if slope < 0.0 {
+            "DOWN"
+        } else {
+            "SIDEWAYS"
+        };
         out.push(trend.to_string());
     }
     out
@@ -0,0 +1,170 @@
+use pyo3::prelude::*;
+
+#[pyclass]
+#[derive(Clone)]
+pub struct PerfStats {
+    #[pyo3(get)]
+    pub total_return: f64,
+    #[pyo3(get)]
+    pub cagr: f64,
+    #[pyo3(get)]
+    pub sharpe_ratio: f64,
+    #[pyo3(get)]
+    pub sortino_ratio: f64,
+    #[pyo3(get)]
+    pub max_drawdown: f64,
+    #[pyo3(get)]
+    pub win_rate: f64,
+    #[pyo3(get)]
+    pub profit_factor: f64,
+    #[pyo3(get)]
+    pub avg_win: f64,
+    #[pyo3(get)]
+    pub avg_loss: f64,
+    #[pyo3(get)]
+    pub trade_count: usize,
+    #[pyo3(get)]
+    pub equity_curve: Vec<f64>,
+}
+
+/// Simulates a long/flat/short position from a BUY/SELL/HOLD signal stream and
+/// scores it with standard backtest performance metrics.
+pub fn evaluate(close: &[f64], signals: &[String], periods_per_year: f64, fee_bps: f64) -> PerfStats {
+    let n = close.len().min(signals.len());
+    if n < 2 {
+        return PerfStats {
+            total_return: 0.0,
+            cagr: 0.0,
+            sharpe_ratio: 0.0,
+            sortino_ratio: 0.0,
+            max_drawdown: 0.0,
+            win_rate: 0.0,
+            profit_factor: 0.0,
+            avg_win: 0.0,
+            avg_loss: 0.0,
+            trade_count: 0,
+            equity_curve: vec![1.0; n],
+        };
+    }
+
+    let positions: Vec<f64> = signals[..n].iter().map(|s| match s.as_str() {
+        "BUY" => 1.0,
+        "SELL" => -1.0,
+        _ => 0.0,
+    }).collect();
+
+    let fee_rate = fee_bps / 10_000.0;
+    let mut returns = vec![0.0; n];
+    let mut equity_curve = vec![1.0; n];
+    for i in 1..n {
+        let prev_close = close[i - 1];
+        let raw_ret = if prev_close.abs() > 1e-12 { close[i] / prev_close - 1.0 } else { 0.0 };
+        let prev_position = if i >= 2 { positions[i - 2] } else { 0.0 };
+        let turnover = (positions[i - 1] - prev_position).abs();
+        let fee = turnover * fee_rate;
+        returns[i] = positions[i - 1] * raw_ret - fee;
+        equity_curve[i] = equity_curve[i - 1] * (1.0 + returns[i]);
+    }
+
+    let total_return = equity_curve[n - 1] - 1.0;
+    let cagr = if equity_curve[n - 1] > 0.0 {
+        equity_curve[n - 1].powf(periods_per_year / n as f64) - 1.0
+    } else {
+        -1.0
+    };
+
+    let bar_returns = &returns[1..];
+    let mean_ret = mean(bar_returns);
+    let std_ret = stddev(bar_returns, mean_ret);
+    let sharpe_ratio = if std_ret > 1e-12 { mean_ret / std_ret * periods_per_year.sqrt() } else { 0.0 };
+
+    let downside: Vec<f64> = bar_returns.iter().map(|r| r.min(0.0)).collect();
+    let downside_std = (downside.iter().map(|d| d * d).sum::<f64>() / downside.len().max(1) as f64).sqrt();
+    let sortino_ratio = if downside_std > 1e-12 { mean_ret / downside_std * periods_per_year.sqrt() } else { 0.0 };
+
+    let max_drawdown = max_drawdown(&equity_curve);
+
+    let (win_rate, profit_factor, avg_win, avg_loss, trade_count) = trade_stats(&positions, bar_returns);
+
+    PerfStats {
+        total_return,
+        cagr,
+        sharpe_ratio,
+        sortino_ratio,
+        max_drawdown,
+        win_rate,
+        profit_factor,
+        avg_win,
+        avg_loss,
+        trade_count,
+        equity_curve,
+    }
+}
+
+fn max_drawdown(equity_curve: &[f64]) -> f64 {
+    let mut peak = equity_curve.first().copied().unwrap_or(1.0);
+    let mut max_dd = 0.0;
+    for &e in equity_curve {
+        if e > peak {
+            peak = e;
+        }
+        let dd = if peak > 0.0 { (peak - e) / peak } else { 0.0 };
+        if dd > max_dd {
+            max_dd = dd;
+        }
+    }
+    max_dd
+}
+
+/// Accumulates P&L per contiguous same-position segment (a "trade"), closing it
+/// out whenever the position changes — including a direct sign flip with no
+/// intervening flat bar — then derives win rate/profit factor from the
+/// resulting trade P&L distribution.
+fn trade_stats(positions: &[f64], bar_returns: &[f64]) -> (f64, f64, f64, f64, usize) {
+    let mut trade_pnls: Vec<f64> = Vec::new();
+    let mut current_pnl = 0.0;
+    let mut current_position = 0.0;
+
+    for i in 0..bar_returns.len() {
+        let pos = positions[i]; // position held going into bar i+1, matches bar_returns[i]
+        if pos != current_position {
+            if current_position != 0.0 {
+                trade_pnls.push(current_pnl);
+            }
+            current_pnl = 0.0;
+            current_position = pos;
+        }
+        if pos != 0.0 {
+            current_pnl += bar_returns[i];
+        }
+    }
+    if current_position != 0.0 {
+        trade_pnls.push(current_pnl);
+    }
+
+    if trade_pnls.is_empty() {
+        return (0.0, 0.0, 0.0, 0.0, 0);
+    }
+
+    let wins: Vec<f64> = trade_pnls.iter().copied().filter(|p| *p > 0.0).collect();
+    let losses: Vec<f64> = trade_pnls.iter().copied().filter(|p| *p < 0.0).collect();
+
+    let win_rate = wins.len() as f64 / trade_pnls.len() as f64;
+    let gross_win: f64 = wins.iter().sum();
+    let gross_loss: f64 = losses.iter().map(|l| l.abs()).sum();
+    let profit_factor = if gross_loss > 1e-12 { gross_win / gross_loss } else { 0.0 };
+    let avg_win = if wins.is_empty() { 0.0 } else { gross_win / wins.len() as f64 };
+    let avg_loss = if losses.is_empty() { 0.0 } else { losses.iter().sum::<f64>() / losses.len() as f64 };
+
+    (win_rate, profit_factor, avg_win, avg_loss, trade_pnls.len())
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() { return 0.0; }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+fn stddev(values: &[f64], mean: f64) -> f64 {
+    if values.is_empty() { return 0.0; }
+    (values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / values.len() as f64).sqrt()
+}
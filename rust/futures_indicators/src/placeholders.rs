@@ -10,7 +10,6 @@ pub mod ensemble_methods {}
 pub mod feature_engineering {}
 pub mod futures_evaluation_framework {}
 pub mod futures_timeseries_analyzer {}
-pub mod garch_analyzer {}
 pub mod rl_models {}
 pub mod seasonal_arima_analyzer {}
 pub mod testing_framework {}
@@ -1,5 +1,7 @@
 use pyo3::prelude::*;
 
+use crate::garch_analyzer;
+
 #[pyclass]
 #[derive(Clone)]
 pub struct SamuelsonBackwardationResult {
@@ -7,15 +9,27 @@ pub struct SamuelsonBackwardationResult {
     pub samuelson_effect: f64,
     #[pyo3(get)]
     pub backwardation_signal: f64,
+    #[pyo3(get)]
+    pub garch_persistence: f64,
+    #[pyo3(get)]
+    pub forecast_volatility: f64,
 }
 
 #[pyfunction]
 pub fn analyze_samuelson(close: Vec<f64>, basis: Option<Vec<f64>>) -> PyResult<SamuelsonBackwardationResult> {
-    // Simple volatility ratio as proxy for Samuelson effect
-    let vol_short = rolling_std(&close, 10).last().cloned().unwrap_or(0.0);
-    let vol_long = rolling_std(&close, 30).last().cloned().unwrap_or(0.0);
-    let ratio = if vol_long <= 1e-12 { 0.0 } else { vol_short / vol_long };
-    let samuelson_effect = (0.5 + (ratio - 1.0) * 0.25).clamp(0.0, 1.0);
+    // Volatility clustering is estimated directly from a fitted GARCH(1,1) rather
+    // than a short/long rolling-std ratio: higher persistence (alpha+beta) means
+    // volatility clusters more strongly as contracts approach expiry. Raw
+    // persistence for real return series is almost always in the 0.85-0.99
+    // IGARCH-like band, so `samuelson_effect` rebases it around that band's
+    // typical value (0.90) rather than 0.0 to give it a genuine neutral midpoint
+    // at 0.5, the scale `unified_analyze`'s term-structure score assumes.
+    let returns = simple_returns(&close);
+    let garch = garch_analyzer::fit_garch11(&returns, 1);
+    const TYPICAL_PERSISTENCE: f64 = 0.90;
+    const PERSISTENCE_SPAN: f64 = 0.10;
+    let samuelson_effect = (((garch.persistence - TYPICAL_PERSISTENCE) / PERSISTENCE_SPAN) + 0.5).clamp(0.0, 1.0);
+    let forecast_volatility = garch.forecast.first().copied().unwrap_or(garch.long_run_variance).max(0.0).sqrt();
 
     let backwardation_signal = match basis {
         Some(b) if !b.is_empty() => {
@@ -25,27 +39,21 @@ pub fn analyze_samuelson(close: Vec<f64>, basis: Option<Vec<f64>>) -> PyResult<S
         _ => 0.5,
     };
 
-    Ok(SamuelsonBackwardationResult { samuelson_effect, backwardation_signal })
+    Ok(SamuelsonBackwardationResult {
+        samuelson_effect,
+        backwardation_signal,
+        garch_persistence: garch.persistence,
+        forecast_volatility,
+    })
 }
 
-fn rolling_std(data: &[f64], window: usize) -> Vec<f64> {
-    let n = data.len();
-    let mut out = vec![0.0; n];
-    let mut sum = 0.0;
-    let mut sum_sq = 0.0;
-    for i in 0..n {
-        let x = data[i];
-        sum += x;
-        sum_sq += x * x;
-        if i >= window { 
-            let x_old = data[i - window];
-            sum -= x_old;
-            sum_sq -= x_old * x_old;
+fn simple_returns(close: &[f64]) -> Vec<f64> {
+    let mut out = Vec::with_capacity(close.len().saturating_sub(1));
+    for i in 1..close.len() {
+        let prev = close[i - 1];
+        if prev.abs() > 1e-12 {
+            out.push((close[i] - prev) / prev);
         }
-        let count = if i + 1 < window { i + 1 } else { window } as f64;
-        let mean = sum / count;
-        let var = (sum_sq / count) - mean * mean;
-        out[i] = var.max(0.0).sqrt();
     }
     out
 }
\ No newline at end of file
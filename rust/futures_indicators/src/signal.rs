@@ -0,0 +1,85 @@
+use pyo3::prelude::*;
+
+use crate::tech;
+
+#[pyclass]
+#[derive(Clone)]
+pub struct CompositeSignalResult {
+    #[pyo3(get)]
+    pub signals: Vec<String>,
+    #[pyo3(get)]
+    pub stop_levels: Vec<f64>,
+    #[pyo3(get)]
+    pub adx: Vec<f64>,
+    #[pyo3(get)]
+    pub atr: Vec<f64>,
+}
+
+/// Fuses a fast/slow moving-average crossover with RSI confirmation and an ADX
+/// trend-strength gate into BUY/SELL/HOLD strings, with ATR-scaled stop levels.
+pub fn composite_signal(
+    high: &[f64],
+    low: &[f64],
+    close: &[f64],
+    fast_period: usize,
+    slow_period: usize,
+    rsi_period: usize,
+    adx_period: usize,
+    adx_threshold: f64,
+    atr_period: usize,
+    atr_multiplier: f64,
+) -> CompositeSignalResult {
+    let fast_ma = sma(close, fast_period);
+    let slow_ma = sma(close, slow_period);
+    let rsi_values = tech::rsi(close, rsi_period);
+    let (adx_values, _plus_di, _minus_di) = tech::adx(high, low, close, adx_period);
+    let atr_values = tech::atr(high, low, close, atr_period);
+
+    let n = close.len().min(fast_ma.len()).min(slow_ma.len()).min(rsi_values.len()).min(adx_values.len()).min(atr_values.len());
+    let mut signals = Vec::with_capacity(n);
+    let mut stop_levels = Vec::with_capacity(n);
+
+    for i in 0..n {
+        let trending = adx_values[i] > adx_threshold;
+        let bullish_cross = fast_ma[i] > slow_ma[i] && rsi_values[i] > 50.0;
+        let bearish_cross = fast_ma[i] < slow_ma[i] && rsi_values[i] < 50.0;
+
+        let signal = if trending && bullish_cross {
+            "BUY"
+        } else if trending && bearish_cross {
+            "SELL"
+        } else {
+            "HOLD"
+        };
+        signals.push(signal.to_string());
+
+        let stop = match signal {
+            "BUY" => close[i] - atr_multiplier * atr_values[i],
+            "SELL" => close[i] + atr_multiplier * atr_values[i],
+            _ => close[i],
+        };
+        stop_levels.push(stop);
+    }
+
+    CompositeSignalResult {
+        signals,
+        stop_levels,
+        adx: adx_values,
+        atr: atr_values,
+    }
+}
+
+fn sma(values: &[f64], period: usize) -> Vec<f64> {
+    let n = values.len();
+    let mut out = vec![0.0; n];
+    let mut sum = 0.0;
+    for i in 0..n {
+        sum += values[i];
+        if i >= period {
+            sum -= values[i - period];
+        }
+        let count = if i + 1 < period { i + 1 } else { period } as f64;
+        out[i] = sum / count;
+    }
+    out
+}
@@ -97,4 +97,178 @@ pub fn williams_r(high: &[f64], low: &[f64], close: &[f64], period: usize) -> Ve
         wr[i] = -100.0 * ((hh - close[i]) / denom);
     }
     wr
+}
+
+fn true_range(high: &[f64], low: &[f64], close: &[f64]) -> Vec<f64> {
+    let n = close.len();
+    let mut tr = vec![0.0; n];
+    for i in 0..n {
+        if i == 0 {
+            tr[i] = high[i] - low[i];
+        } else {
+            let hl = high[i] - low[i];
+            let hc = (high[i] - close[i - 1]).abs();
+            let lc = (low[i] - close[i - 1]).abs();
+            tr[i] = hl.max(hc).max(lc);
+        }
+    }
+    tr
+}
+
+/// Wilder's smoothing: seeded by the simple average of the first `period` values,
+/// then recursively smoothed as prev*(period-1)/period + current/period.
+fn wilder_smooth(values: &[f64], period: usize) -> Vec<f64> {
+    let n = values.len();
+    let mut out = vec![0.0; n];
+    if n == 0 || period == 0 {
+        return out;
+    }
+    if n < period {
+        let avg = values.iter().sum::<f64>() / n as f64;
+        out.iter_mut().for_each(|v| *v = avg);
+        return out;
+    }
+    let seed = values[..period].iter().sum::<f64>() / period as f64;
+    out[period - 1] = seed;
+    for i in 0..period - 1 {
+        out[i] = seed;
+    }
+    for i in period..n {
+        out[i] = (out[i - 1] * (period as f64 - 1.0) + values[i]) / period as f64;
+    }
+    out
+}
+
+/// Average True Range using Wilder's smoothing method.
+pub fn atr(high: &[f64], low: &[f64], close: &[f64], period: usize) -> Vec<f64> {
+    let tr = true_range(high, low, close);
+    wilder_smooth(&tr, period)
+}
+
+/// Average Directional Index with the +DI/-DI directional indicators, using Wilder smoothing.
+/// Returns (adx, plus_di, minus_di).
+pub fn adx(high: &[f64], low: &[f64], close: &[f64], period: usize) -> (Vec<f64>, Vec<f64>, Vec<f64>) {
+    let n = close.len();
+    let mut plus_dm = vec![0.0; n];
+    let mut minus_dm = vec![0.0; n];
+    for i in 1..n {
+        let up_move = high[i] - high[i - 1];
+        let down_move = low[i - 1] - low[i];
+        plus_dm[i] = if up_move > down_move && up_move > 0.0 { up_move } else { 0.0 };
+        minus_dm[i] = if down_move > up_move && down_move > 0.0 { down_move } else { 0.0 };
+    }
+
+    let tr = true_range(high, low, close);
+    let atr_smoothed = wilder_smooth(&tr, period);
+    let plus_dm_smoothed = wilder_smooth(&plus_dm, period);
+    let minus_dm_smoothed = wilder_smooth(&minus_dm, period);
+
+    let mut plus_di = vec![0.0; n];
+    let mut minus_di = vec![0.0; n];
+    let mut dx = vec![0.0; n];
+    for i in 0..n {
+        let atr_i = atr_smoothed[i].max(1e-12);
+        plus_di[i] = 100.0 * plus_dm_smoothed[i] / atr_i;
+        minus_di[i] = 100.0 * minus_dm_smoothed[i] / atr_i;
+        let sum_di = plus_di[i] + minus_di[i];
+        dx[i] = if sum_di <= 1e-12 { 0.0 } else { 100.0 * (plus_di[i] - minus_di[i]).abs() / sum_di };
+    }
+    let adx = wilder_smooth(&dx, period);
+
+    (adx, plus_di, minus_di)
+}
+
+/// Corwin-Schultz (2012) high-low bid-ask spread estimator, rolling-averaged over
+/// `period` bars. Each two-bar estimate uses beta = the sum of (ln(H/L))^2 over the pair
+/// and gamma = (ln(H_max/L_min))^2 spanning both bars, combined into
+/// alpha = (sqrt(2*beta) - sqrt(beta)) / (3 - 2*sqrt(2)) - sqrt(gamma / (3 - 2*sqrt(2))),
+/// and spread S = 2*(e^alpha - 1) / (1 + e^alpha). The first bar has no prior bar to
+/// pair with, so it is padded with zero. Optionally applies the same overnight-gap
+/// correction as `corwin_schultz` when `close` is supplied.
+pub fn corwin_schultz_spread(high: &[f64], low: &[f64], close: Option<&[f64]>, period: usize) -> Vec<f64> {
+    let raw = corwin_schultz_raw(high, low, close);
+    rolling_mean(&raw, period.max(1))
+}
+
+/// Per-bar Corwin-Schultz effective spread with no rolling smoothing, optionally
+/// correcting each pair's second bar for an overnight gap when `close` is supplied:
+/// the high/low are shifted by `max(0, C_t - H_{t+1}) + min(0, C_t - L_{t+1})` so a
+/// gap isn't mistaken for intrabar range. Output is front-padded with zero for the
+/// first bar, which has no prior bar to pair with.
+pub fn corwin_schultz(high: &[f64], low: &[f64], close: Option<&[f64]>) -> Vec<f64> {
+    corwin_schultz_raw(high, low, close)
+}
+
+const CORWIN_SCHULTZ_K: f64 = 3.0 - 2.0 * std::f64::consts::SQRT_2;
+
+fn corwin_schultz_raw(high: &[f64], low: &[f64], close: Option<&[f64]>) -> Vec<f64> {
+    let n = high.len().min(low.len());
+    let mut raw = vec![0.0; n];
+
+    for i in 1..n {
+        let (mut h1, mut l1) = (high[i], low[i]);
+        if let Some(c) = close {
+            let prev_close = c[i - 1];
+            let gap = (prev_close - h1).max(0.0) + (prev_close - l1).min(0.0);
+            h1 += gap;
+            l1 += gap;
+        }
+
+        if h1 <= 0.0 || l1 <= 0.0 || high[i - 1] <= 0.0 || low[i - 1] <= 0.0 {
+            continue;
+        }
+        let beta = (h1 / l1).ln().powi(2) + (high[i - 1] / low[i - 1]).ln().powi(2);
+        let h_max = h1.max(high[i - 1]);
+        let l_min = l1.min(low[i - 1]);
+        let gamma = (h_max / l_min).ln().powi(2);
+
+        let alpha = ((2.0 * beta).sqrt() - beta.sqrt()) / CORWIN_SCHULTZ_K - (gamma / CORWIN_SCHULTZ_K).sqrt();
+        let alpha = alpha.max(0.0);
+        let spread = 2.0 * (alpha.exp() - 1.0) / (1.0 + alpha.exp());
+        raw[i] = spread.max(0.0);
+    }
+
+    raw
+}
+
+/// Cross-sectional RSI ranking over a multi-asset universe: computes the latest
+/// RSI for each asset column and returns the indices of the `top_n` highest-RSI
+/// assets as longs and the `top_n` lowest as shorts, ties broken by index. Assets
+/// with fewer than `period+1` observations are excluded from ranking.
+pub fn rsi_rank(closes: &Vec<Vec<f64>>, period: usize, top_n: usize) -> (Vec<usize>, Vec<usize>) {
+    let mut ranked: Vec<(usize, f64)> = Vec::with_capacity(closes.len());
+    for (idx, series) in closes.iter().enumerate() {
+        if series.len() < period + 1 {
+            continue;
+        }
+        let values = rsi(series, period);
+        if let Some(latest) = values.last() {
+            ranked.push((idx, *latest));
+        }
+    }
+
+    let mut by_rsi = ranked.clone();
+    by_rsi.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap().then(a.0.cmp(&b.0)));
+    let longs: Vec<usize> = by_rsi.iter().take(top_n).map(|(idx, _)| *idx).collect();
+
+    let mut by_rsi_asc = ranked;
+    by_rsi_asc.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap().then(a.0.cmp(&b.0)));
+    let shorts: Vec<usize> = by_rsi_asc.iter().take(top_n).map(|(idx, _)| *idx).collect();
+
+    (longs, shorts)
+}
+
+fn rolling_mean(values: &[f64], period: usize) -> Vec<f64> {
+    let n = values.len();
+    let mut out = vec![0.0; n];
+    let mut sum = 0.0;
+    for i in 0..n {
+        sum += values[i];
+        if i >= period {
+            sum -= values[i - period];
+        }
+        let count = if i + 1 < period { i + 1 } else { period } as f64;
+        out[i] = sum / count;
+    }
+    out
 }
\ No newline at end of file
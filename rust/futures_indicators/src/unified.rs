@@ -1,8 +1,9 @@
 use pyo3::prelude::*;
 
-use crate::momentum;
 use crate::mean_reversion;
+use crate::momentum;
 use crate::samuelson;
+use crate::tech;
 
 #[pyclass]
 #[derive(Clone)]
@@ -17,10 +18,24 @@ pub struct FuturesUnifiedResult {
     pub term_structure_score: f64,
     #[pyo3(get)]
     pub trading_signals: Vec<String>,
+    #[pyo3(get)]
+    pub stop_loss: f64,
+    #[pyo3(get)]
+    pub take_profit: f64,
+    #[pyo3(get)]
+    pub atr_value: f64,
 }
 
 #[pyfunction]
-pub fn unified_analyze(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>, volume: Vec<f64>, basis: Option<Vec<f64>>) -> PyResult<FuturesUnifiedResult> {
+pub fn unified_analyze(
+    high: Vec<f64>,
+    low: Vec<f64>,
+    close: Vec<f64>,
+    volume: Vec<f64>,
+    basis: Option<Vec<f64>>,
+    atr_multiple: Option<f64>,
+    reward_multiple: Option<f64>,
+) -> PyResult<FuturesUnifiedResult> {
     let momentum_res = momentum::analyze(&high, &low, &close, &volume);
     let mean_rev_res = mean_reversion::analyze_mean_reversion(high.clone(), low.clone(), close.clone())?;
     let sam_res = samuelson::analyze_samuelson(close.clone(), basis.clone())?;
@@ -36,9 +51,13 @@ pub fn unified_analyze(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>, volume: V
     // Consensus signal is weighted sum
     let consensus_signal = (technical_score * 0.7 + term_structure_score * 0.3).clamp(-1.0, 1.0);
 
-    // Confidence from strength and volatility stability
+    // Confidence from strength, volatility stability, and liquidity: a wide
+    // effective spread means entries/exits are costlier to trust, so it discounts
+    // confidence rather than the directional signal itself.
     let strength = if momentum_res.momentum_strength.is_empty() { 0.0 } else { *momentum_res.momentum_strength.last().unwrap() };
-    let confidence = (0.5 + 0.5 * strength).clamp(0.0, 1.0);
+    let spread = tech::corwin_schultz(&high, &low, Some(&close));
+    let last_spread = spread.last().copied().unwrap_or(0.0);
+    let confidence = ((0.5 + 0.5 * strength) * (1.0 - last_spread.clamp(0.0, 1.0))).clamp(0.0, 1.0);
 
     // Trading signals join
     let mut signals = Vec::new();
@@ -46,11 +65,28 @@ pub fn unified_analyze(high: Vec<f64>, low: Vec<f64>, close: Vec<f64>, volume: V
     if let Some(s) = mean_rev_res.reversion_signals.last() { signals.push(format!("MeanReversion: {}", s)); }
     signals.push(format!("TermStructure: {:.2}", term_structure_score));
 
+    // ATR-based stop-loss/take-profit bracket around the last close, sized off
+    // the consensus signal's direction: the stop sits k*ATR against the trade,
+    // the target r*k*ATR in its favor.
+    let k = atr_multiple.unwrap_or(2.0);
+    let r = reward_multiple.unwrap_or(1.5);
+    let atr_series = tech::atr(&high, &low, &close, 14);
+    let atr_value = atr_series.last().copied().unwrap_or(0.0);
+    let last_close = close.last().copied().unwrap_or(0.0);
+    let (stop_loss, take_profit) = if consensus_signal > 0.0 {
+        (last_close - k * atr_value, last_close + r * k * atr_value)
+    } else {
+        (last_close + k * atr_value, last_close - r * k * atr_value)
+    };
+
     Ok(FuturesUnifiedResult {
         consensus_signal,
         consensus_confidence: confidence,
         technical_score,
         term_structure_score,
         trading_signals: signals,
+        stop_loss,
+        take_profit,
+        atr_value,
     })
 }
\ No newline at end of file